@@ -1,9 +1,8 @@
-use axum::{
-    response::{sse::Event, Sse},
-    Router,
-};
+use axum::Router;
 use futures_core::Stream;
+use futures_util::StreamExt;
 use rand_core::{OsRng, RngCore};
+use sse_agent::{Event, IntoSseBytes};
 use std::{
     convert::Infallible,
     pin::Pin,
@@ -26,8 +25,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error + 'static>> {
     Ok(())
 }
 
-async fn event_stream() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    Sse::new(EventStream::default())
+async fn event_stream() -> impl axum::response::IntoResponse {
+    let body = hyper::Body::wrap_stream(
+        EventStream::default()
+            .into_sse_bytes()
+            .map(Ok::<_, Infallible>),
+    );
+
+    axum::http::Response::builder()
+        .header("Content-Type", "text/event-stream")
+        .body(body)
+        .expect("response")
 }
 
 #[derive(Default)]
@@ -36,7 +44,8 @@ pub struct EventStream {
 }
 
 impl Stream for EventStream {
-    type Item = Result<Event, Infallible>;
+    type Item = Event;
+
     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let event = match OsRng.next_u32() % 4 {
             0 => Event::default().data("DataEvent"),
@@ -48,6 +57,6 @@ impl Stream for EventStream {
         self.counter += 1;
         let event = event.id(self.counter.to_string());
         println!("Returning Event: {:?}", event);
-        Poll::Ready(Some(Ok(event)))
+        Poll::Ready(Some(event))
     }
 }
@@ -1,16 +1,91 @@
 use futures_core::Stream;
 use std::{
     error::Error as StdError,
+    future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
+use tokio::time::{sleep, Instant, Sleep};
 
-use crate::{parser::Parser, Error, Event};
+use crate::{parser::Parser, Error, Event, Frame};
 
 pub struct Body<S> {
     inner: S,
 
     parser: Parser,
+    idle: Option<IdleTimer>,
+}
+
+/// A reset-on-data timer backing `Body::with_idle_timeout`.
+struct IdleTimer {
+    duration: Duration,
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl IdleTimer {
+    fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            sleep: Box::pin(sleep(duration)),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.sleep.as_mut().reset(Instant::now() + self.duration);
+    }
+
+    fn poll(&mut self, ctx: &mut Context) -> Poll<()> {
+        match self.sleep.as_mut().poll(ctx) {
+            Poll::Ready(()) => {
+                // Re-arm immediately so a quiet stream surfaces the
+                // error once per `duration` instead of spinning on an
+                // already-fired `Sleep` forever.
+                self.reset();
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S> Body<S> {
+    /// Returns the most recently parsed `retry:` directive, if the server
+    /// has sent one so far.
+    pub fn retry(&self) -> Option<Duration> {
+        self.parser.retry()
+    }
+
+    /// Turns this `Body` into a `Frames`, which yields every directive
+    /// the parser observes (comments, `retry:` directives, dispatched
+    /// events) instead of only dispatched events.
+    pub fn frames(self) -> Frames<S> {
+        Frames { inner: self }
+    }
+
+    /// Arms an idle timeout: if no bytes arrive from the underlying
+    /// stream within `duration`, `poll_next` yields an
+    /// `ErrorKind::IdleTimeout` error instead of hanging on
+    /// `Poll::Pending` forever. The timer is reset every time data
+    /// arrives, so a server sending periodic keep-alive comments keeps it
+    /// from firing.
+    pub fn with_idle_timeout(mut self, duration: Duration) -> Self {
+        self.idle = Some(IdleTimer::new(duration));
+        self
+    }
+
+    /// Deserializes every event's `data` field as `T`.
+    #[cfg(feature = "json")]
+    pub fn typed<T>(self) -> crate::typed::Typed<S, T> {
+        crate::typed::Typed::new(self, None)
+    }
+
+    /// Like `typed`, but skips events whose `event` field doesn't match
+    /// `event`.
+    #[cfg(feature = "json")]
+    pub fn typed_named<T>(self, event: impl Into<String>) -> crate::typed::Typed<S, T> {
+        crate::typed::Typed::new(self, Some(event.into()))
+    }
 }
 
 impl<S, B, E> Stream for Body<S>
@@ -19,7 +94,7 @@ where
     B: bytes::Buf,
     E: StdError + Unpin,
 {
-    type Item = Result<Event, Error>;
+    type Item = Result<Event, Error<E>>;
 
     fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
         // Whenever the parser cannot yet produce an Event. We want to poll the underlying
@@ -39,8 +114,65 @@ where
             match Pin::new(&mut self.inner).poll_next(ctx) {
                 Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(Error::inner(err)))),
                 Poll::Ready(None) => return Poll::Ready(None),
-                Poll::Pending => return Poll::Pending,
-                Poll::Ready(Some(Ok(bs))) => self.parser.put(bs),
+                Poll::Ready(Some(Ok(bs))) => {
+                    if let Some(idle) = &mut self.idle {
+                        idle.reset();
+                    }
+                    self.parser.put(bs)
+                }
+                Poll::Pending => {
+                    if let Some(idle) = &mut self.idle {
+                        if idle.poll(ctx).is_ready() {
+                            return Poll::Ready(Some(Err(Error::idle_timeout())));
+                        }
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// A parallel view onto a `Body`'s underlying stream that yields every
+/// `Frame` the parser sees, not just dispatched `Event`s. See
+/// `Body::frames`.
+pub struct Frames<S> {
+    inner: Body<S>,
+}
+
+impl<S, B, E> Stream for Frames<S>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: bytes::Buf,
+    E: StdError + Unpin,
+{
+    type Item = Result<Frame, Error<E>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.inner.parser.next_frame() {
+                Some(Ok(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Some(Err(err)) => return Poll::Ready(Some(Err(Error::parser(err)))),
+                None => (),
+            }
+
+            match Pin::new(&mut self.inner.inner).poll_next(ctx) {
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(Error::inner(err)))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Ok(bs))) => {
+                    if let Some(idle) = &mut self.inner.idle {
+                        idle.reset();
+                    }
+                    self.inner.parser.put(bs)
+                }
+                Poll::Pending => {
+                    if let Some(idle) = &mut self.inner.idle {
+                        if idle.poll(ctx).is_ready() {
+                            return Poll::Ready(Some(Err(Error::idle_timeout())));
+                        }
+                    }
+                    return Poll::Pending;
+                }
             }
         }
     }
@@ -56,6 +188,48 @@ where
         Self {
             inner,
             parser: Parser::default(),
+            idle: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use futures_util::{stream, StreamExt};
+    use std::convert::Infallible;
+
+    #[tokio::test]
+    async fn idle_timeout_fires_once_on_a_quiet_stream() {
+        let inner = stream::pending::<Result<Bytes, Infallible>>();
+        let mut body = Body::from(inner).with_idle_timeout(Duration::from_millis(5));
+
+        let err = body.next().await.expect("Some").expect_err("IdleTimeout");
+        assert!(matches!(err.kind(), crate::ErrorKind::IdleTimeout));
+
+        // The timer must have re-armed after firing: polling again right
+        // away should not immediately repeat the same error.
+        let result = tokio::time::timeout(Duration::from_millis(2), body.next()).await;
+        assert!(result.is_err(), "idle timeout fired again without a reset");
+    }
+
+    #[tokio::test]
+    async fn idle_timeout_is_suppressed_by_incoming_bytes() {
+        // Each tick sleeps for less than the idle timeout before sending
+        // a keep-alive comment, so the timer keeps getting reset instead
+        // of ever firing.
+        let ticks = stream::unfold(0u8, |n| async move {
+            if n < 3 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                Some((Ok::<_, Infallible>(Bytes::from(": ping\n\n")), n + 1))
+            } else {
+                None
+            }
+        });
+
+        let mut body = Body::from(Box::pin(ticks)).with_idle_timeout(Duration::from_millis(20));
+
+        assert!(body.next().await.is_none());
+    }
+}
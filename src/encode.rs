@@ -0,0 +1,46 @@
+use bytes::{Bytes, BytesMut};
+use futures_core::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::Event;
+
+/// Adapts a `Stream<Item = Event>` into a `Stream<Item = Bytes>` by
+/// serializing every event with [`Event::encode`]. See [`IntoSseBytes`].
+pub struct SseEncoder<S> {
+    inner: S,
+}
+
+impl<S> Stream for SseEncoder<S>
+where
+    S: Stream<Item = Event> + Unpin,
+{
+    type Item = Bytes;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.inner).poll_next(ctx) {
+            Poll::Ready(Some(ev)) => {
+                let mut buf = BytesMut::new();
+                ev.encode(&mut buf);
+                Poll::Ready(Some(buf.freeze()))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Turns a `Stream<Item = Event>` into wire-format `Bytes`, ready to be
+/// written to a hyper/axum response body. The counterpart of [`Sse`] on
+/// the server side.
+///
+/// [`Sse`]: crate::Sse
+pub trait IntoSseBytes: Stream<Item = Event> + Sized {
+    fn into_sse_bytes(self) -> SseEncoder<Self> {
+        SseEncoder { inner: self }
+    }
+}
+
+impl<S> IntoSseBytes for S where S: Stream<Item = Event> {}
@@ -19,6 +19,9 @@ where
         match self.kind.as_ref() {
             ErrorKind::Inner(ref err) => write!(f, "Transport error: {}", err),
             ErrorKind::Sse(err) => write!(f, "Parse error: {}", err),
+            ErrorKind::IdleTimeout => write!(f, "Idle timeout: no data received in time"),
+            #[cfg(feature = "json")]
+            ErrorKind::Deserialize(err) => write!(f, "Deserialize error: {}", err),
         }
     }
 }
@@ -31,6 +34,9 @@ where
         match self.kind.as_ref() {
             ErrorKind::Inner(ref err) => err.source(),
             ErrorKind::Sse(err) => err.source(),
+            ErrorKind::IdleTimeout => None,
+            #[cfg(feature = "json")]
+            ErrorKind::Deserialize(_) => None,
         }
     }
 }
@@ -47,10 +53,29 @@ impl<E> Error<E> {
             kind: Box::new(ErrorKind::Sse(err)),
         }
     }
+
+    pub(crate) fn idle_timeout() -> Self {
+        Self {
+            kind: Box::new(ErrorKind::IdleTimeout),
+        }
+    }
+
+    // Takes `impl Display` rather than `serde_json::Error` directly so the
+    // core crate doesn't need a hard dependency on serde_json, and so this
+    // type keeps deriving `Clone` (serde_json::Error itself doesn't).
+    #[cfg(feature = "json")]
+    pub(crate) fn deserialize(err: impl fmt::Display) -> Self {
+        Self {
+            kind: Box::new(ErrorKind::Deserialize(err.to_string())),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum ErrorKind<E> {
     Sse(crate::parser::Error),
     Inner(E),
+    IdleTimeout,
+    #[cfg(feature = "json")]
+    Deserialize(String),
 }
@@ -1,6 +1,142 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use bytes::{BufMut, BytesMut};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Event {
     pub event: String,
     pub data: String,
     pub last_event_id: Option<String>,
+
+    // These two are write-only: the parser never populates them on an
+    // `Event` it hands back (see `Parser::retry` and `Frame` for how
+    // comments/retries are observed on the read side), but a
+    // server wants to be able to attach them to the block it is about to
+    // write out.
+    pub comment: Option<String>,
+    pub retry: Option<Duration>,
+}
+
+impl Event {
+    /// Sets the `data:` field, replacing any value set previously.
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    /// Sets the `event:` field, replacing any value set previously.
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event = event.into();
+        self
+    }
+
+    /// Sets the `id:` field, replacing any value set previously.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.last_event_id = Some(id.into());
+        self
+    }
+
+    /// Attaches a comment (`: ...`) line to be written ahead of this event.
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Attaches a `retry:` directive to be written ahead of this event.
+    pub fn retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Serializes this event into the SSE wire format, appending it to
+    /// `buf`. Fields left at their default (empty string / `None`) are
+    /// omitted from the output. This is the inverse of `Parser`: encoding
+    /// an event and parsing the result back yields the same `event`,
+    /// `data` and `last_event_id`. An event whose `event` and `data` are
+    /// both empty and which has no id encodes to a bare blank line, which
+    /// the parser does not dispatch.
+    pub fn encode(&self, buf: &mut BytesMut) {
+        if let Some(comment) = &self.comment {
+            for line in comment.split('\n') {
+                buf.put_slice(b": ");
+                buf.put_slice(line.as_bytes());
+                buf.put_u8(b'\n');
+            }
+        }
+
+        if let Some(retry) = self.retry {
+            buf.put_slice(b"retry: ");
+            buf.put_slice(retry.as_millis().to_string().as_bytes());
+            buf.put_u8(b'\n');
+        }
+
+        if !self.event.is_empty() {
+            buf.put_slice(b"event: ");
+            buf.put_slice(self.event.as_bytes());
+            buf.put_u8(b'\n');
+        }
+
+        if !self.data.is_empty() {
+            for line in self.data.split('\n') {
+                buf.put_slice(b"data: ");
+                buf.put_slice(line.as_bytes());
+                buf.put_u8(b'\n');
+            }
+        }
+
+        if let Some(id) = &self.last_event_id {
+            buf.put_slice(b"id: ");
+            buf.put_slice(id.as_bytes());
+            buf.put_u8(b'\n');
+        }
+
+        buf.put_u8(b'\n');
+    }
+}
+
+#[cfg(feature = "json")]
+impl Event {
+    /// Deserializes this event's `data` field as JSON.
+    pub fn json<T>(&self) -> Result<T, crate::Error<std::convert::Infallible>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(&self.data).map_err(crate::Error::deserialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    fn roundtrip(ev: Event) -> Event {
+        let mut buf = BytesMut::new();
+        ev.encode(&mut buf);
+
+        let mut parser = Parser::default();
+        parser.put(buf.freeze());
+        parser.next().expect("Event").expect("Parses")
+    }
+
+    #[test]
+    fn roundtrips_single_line_data() {
+        let ev = Event::default().data("hello");
+        assert_eq!(roundtrip(ev.clone()).data, ev.data);
+    }
+
+    #[test]
+    fn roundtrips_multi_line_data() {
+        let ev = Event::default().data("line one\nline two\nline three");
+        let decoded = roundtrip(ev.clone());
+        assert_eq!(decoded.data, ev.data);
+    }
+
+    #[test]
+    fn roundtrips_event_and_id() {
+        let ev = Event::default().event("update").data("payload").id("42");
+        let decoded = roundtrip(ev.clone());
+        assert_eq!(decoded.event, ev.event);
+        assert_eq!(decoded.data, ev.data);
+        assert_eq!(decoded.last_event_id, ev.last_event_id);
+    }
 }
@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+use crate::Event;
+
+/// Every directive `Parser` can observe in an SSE stream, not just the
+/// `Event`s that `Parser::next` dispatches. Useful for consumers that
+/// need to see comment lines (e.g. to detect a server's keep-alive
+/// heartbeat) or `retry:` directives without having to drive a
+/// reconnection loop themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Frame {
+    Event(Event),
+    Comment(String),
+    Retry(Duration),
+}
@@ -1,16 +1,35 @@
 use {bytes::Bytes, futures_core::Stream};
 
 mod body;
+mod encode;
 mod error;
 mod event;
+mod frame;
 mod parser;
+mod reconnect;
+
+#[cfg(feature = "tokio")]
+mod reader;
+
+#[cfg(feature = "json")]
+mod typed;
 
 pub use {
-    body::Body,
+    body::{Body, Frames},
+    encode::{IntoSseBytes, SseEncoder},
     error::{Error, ErrorKind},
     event::Event,
+    frame::Frame,
+    parser::Parser,
+    reconnect::{EventSource, DEFAULT_RETRY},
 };
 
+#[cfg(feature = "tokio")]
+pub use reader::FromReader;
+
+#[cfg(feature = "json")]
+pub use typed::Typed;
+
 pub trait Sse<S> {
     fn into_sse(self) -> Body<S>;
 }
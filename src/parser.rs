@@ -1,6 +1,6 @@
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use memchr::{memchr, memchr2};
-use std::{error::Error as StdError, fmt, str};
+use std::{error::Error as StdError, fmt, str, time::Duration};
 
 const CR: u8 = b'\r';
 const LF: u8 = b'\n';
@@ -33,6 +33,15 @@ impl StdError for Error {
 struct EventBuilder {
     event_type: Option<String>,
     data: Option<String>,
+
+    // Per spec, every additional `data:` line appends a LF before it.
+    // Rather than pushing that LF into `data` right away (and reserving
+    // for it) on every single line, we just count how many are owed and
+    // flush them in one go the moment more data actually needs writing.
+    // A single-line event only bumps this counter and never flushes it,
+    // so it needs no allocation beyond its own `String`.
+    data_trailing_newlines: usize,
+
     last_event_id: Option<String>,
 }
 
@@ -44,32 +53,27 @@ impl EventBuilder {
             // Set event type buffer to value. After parsing as utf8.
             self.event_type.replace(String::from(value));
         } else if name == &b"data"[..] {
-            // According to the spec
-            // (https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation)
-            // Whenever data is pushed, a single LF should be appended
-            // and then removed whenever an entire event is created.
-            // However this is stupid, better just add a LF before
-            // appending data to an already existing data buffer.
-            // So we push a LF before we add MORE data.
             match &mut self.data {
-                Some(ref mut data) => {
-                    data.reserve(value.len() + 1);
-                    data.push('\n');
+                Some(data) => {
+                    // Flush the newlines owed from previous `data:` lines
+                    // and this value in a single reserve/extend.
+                    data.reserve(self.data_trailing_newlines + value.len());
+                    data.extend(std::iter::repeat_n('\n', self.data_trailing_newlines));
                     data.push_str(value);
+                    self.data_trailing_newlines = 0;
                 }
 
                 None => {
                     self.data = Some(String::from(value));
                 }
             }
+
+            // This line owes a LF before whatever comes next.
+            self.data_trailing_newlines += 1;
         } else if name == &b"id"[..] && !value.contains(NULL) {
             // Set the latest_event_id buffer field.
             // value must not contain any nulls.
             self.last_event_id = Some(String::from(value));
-        } else if name == &b"retry"[..] && value.chars().all(|c| c.is_digit(10)) {
-            // If the field name is retry and the value is all base 10 digits.
-            // use the value as the amount of time to wait before reconnects.
-            // @TODO: Implement reconnection time.
         }
 
         Ok(())
@@ -80,10 +84,14 @@ impl EventBuilder {
     }
 
     fn build_and_clear(&mut self) -> Result<crate::Event, Error> {
+        self.data_trailing_newlines = 0;
+
         Ok(crate::Event {
-            event: self.event_type.take().unwrap_or_else(String::new),
-            data: self.data.take().unwrap_or_else(String::new),
+            event: self.event_type.take().unwrap_or_default(),
+            data: self.data.take().unwrap_or_default(),
             last_event_id: self.last_event_id.take(),
+            comment: None,
+            retry: None,
         })
     }
 }
@@ -92,6 +100,12 @@ impl EventBuilder {
 pub struct Parser {
     buf: BytesMut,
     builder: EventBuilder,
+
+    // The most recently seen `retry:` directive. Unlike the fields on
+    // `EventBuilder` this is not cleared when an event is dispatched, since
+    // per spec the reconnection time stays in effect until a new `retry:`
+    // line overrides it.
+    retry: Option<Duration>,
 }
 
 impl Parser {
@@ -99,21 +113,53 @@ impl Parser {
         self.buf.put(bs)
     }
 
-    /// Parses a line and attemps to add it to the current Builder.
-    ///
+    /// Returns the most recently parsed `retry:` directive, if any has been
+    /// seen so far.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    /// Parses a line and attemps to add it to the current Builder, only
+    /// ever returning dispatched `Event`s. `retry:` directives are still
+    /// recorded (see `Parser::retry`); comments are silently consumed.
+    /// Use `next_frame` to observe both.
     pub fn next(&mut self) -> Option<Result<crate::Event, Error>> {
+        loop {
+            match self.next_frame() {
+                Some(Ok(crate::Frame::Event(ev))) => return Some(Ok(ev)),
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => return Some(Err(err)),
+                None => return None,
+            }
+        }
+    }
+
+    /// Like `next`, but surfaces every directive the parser sees as a
+    /// `Frame`, not just dispatched events.
+    pub fn next_frame(&mut self) -> Option<Result<crate::Frame, Error>> {
         // Parse while there are lines.
 
         while let Some(line) = self.parse_line() {
             if line.is_empty() && self.builder.ready() {
-                return Some(self.builder.build_and_clear());
+                return Some(self.builder.build_and_clear().map(crate::Frame::Event));
             }
 
             // Check if there's a colon in the line
             match memchr(COLON, &line) {
-                // Lines beginning with colon are just skipped
+                // Lines beginning with colon are comments: the text after
+                // the colon (and the optional leading space) is the
+                // comment's contents.
                 Some(0) => {
-                    continue;
+                    let comment = if 1 < line.len() && line[1] == b' ' {
+                        &line[2..]
+                    } else {
+                        &line[1..]
+                    };
+
+                    return match str::from_utf8(comment) {
+                        Ok(s) => Some(Ok(crate::Frame::Comment(String::from(s)))),
+                        Err(err) => Some(Err(Error::Utf8(err))),
+                    };
                 }
 
                 Some(i) => {
@@ -128,6 +174,24 @@ impl Parser {
                         &line[i + 1..]
                     };
 
+                    if name == &b"retry"[..] {
+                        // If the value is all base 10 digits, use it as the
+                        // number of milliseconds to wait before the next
+                        // reconnect attempt. Anything else is ignored, per
+                        // spec.
+                        if let Ok(value) = str::from_utf8(value) {
+                            if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()) {
+                                if let Ok(millis) = value.parse() {
+                                    let retry = Duration::from_millis(millis);
+                                    self.retry = Some(retry);
+                                    return Some(Ok(crate::Frame::Retry(retry)));
+                                }
+                            }
+                        }
+
+                        continue;
+                    }
+
                     // TODO:
                     // 1. Remove potential white space after colon
                     // 2. Verify that lines ending in colon works.
@@ -189,11 +253,25 @@ impl Parser {
     }
 }
 
+#[cfg(feature = "tokio")]
+impl Parser {
+    /// Drives a fresh `Parser` off any `AsyncBufRead` source (a file, a
+    /// TCP socket, anything that isn't an HTTP byte stream), yielding
+    /// `Event`s as they're dispatched.
+    pub fn from_reader<R>(reader: R) -> crate::reader::FromReader<R>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+    {
+        crate::reader::FromReader::new(reader)
+    }
+}
+
 impl From<&[u8]> for Parser {
     fn from(b: &[u8]) -> Self {
         Self {
             buf: BytesMut::from(b),
             builder: EventBuilder::default(),
+            retry: None,
         }
     }
 }
@@ -203,6 +281,7 @@ impl From<&str> for Parser {
         Self {
             buf: BytesMut::from(s),
             builder: EventBuilder::default(),
+            retry: None,
         }
     }
 }
@@ -216,7 +295,7 @@ mod tests {
     fn buf_cleared_line_ending_with_crlf() {
         let mut p = Parser::from("\r\n");
         p.next();
-        assert_eq!(p.bytes(), &[]);
+        assert!(p.bytes().is_empty());
     }
 
     #[test]
@@ -229,14 +308,14 @@ mod tests {
     fn buf_cleared_line_ending_with_cr() {
         let mut p = Parser::from("\r");
         p.next();
-        assert_eq!(p.bytes(), &[]);
+        assert!(p.bytes().is_empty());
     }
 
     #[test]
     fn buf_cleared_line_ending_with_lf() {
         let mut p = Parser::from("\n");
         p.next();
-        assert_eq!(p.bytes(), &[]);
+        assert!(p.bytes().is_empty());
     }
 
     #[test]
@@ -372,6 +451,81 @@ data:  third event
         assert_eq!(ev.last_event_id, None);
     }
 
+    #[test]
+    fn next_frame_surfaces_comments() {
+        let mut p = Parser::from(": keep-alive\n\n");
+        let frame = p.next_frame().expect("Frame").expect("Parses");
+        assert_eq!(frame, crate::Frame::Comment(String::from("keep-alive")));
+    }
+
+    #[test]
+    fn next_frame_surfaces_retry() {
+        let mut p = Parser::from("retry: 1500\n\n");
+        let frame = p.next_frame().expect("Frame").expect("Parses");
+        assert_eq!(frame, crate::Frame::Retry(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn next_frame_surfaces_events() {
+        let mut p = Parser::from("data: hi\n\n");
+        let frame = p.next_frame().expect("Frame").expect("Parses");
+        assert_eq!(
+            frame,
+            crate::Frame::Event(crate::Event::default().data("hi"))
+        );
+    }
+
+    #[test]
+    fn next_skips_comments_and_retries() {
+        let mut p = Parser::from(": hi\n\nretry: 10\n\ndata: hi\n\n");
+        let ev = p.next().expect("Event").expect("Parses");
+        assert_eq!(ev.data, "hi");
+    }
+
+    #[test]
+    fn single_line_data_event() {
+        let mut p = Parser::from("data: one\n\n");
+        let ev = p.next().expect("Event").expect("Parses");
+        assert_eq!(ev.data, "one");
+    }
+
+    #[test]
+    fn two_line_data_event() {
+        let mut p = Parser::from("data: one\ndata: two\n\n");
+        let ev = p.next().expect("Event").expect("Parses");
+        assert_eq!(ev.data, "one\ntwo");
+    }
+
+    #[test]
+    fn three_line_data_event() {
+        let mut p = Parser::from("data: one\ndata: two\ndata: three\n\n");
+        let ev = p.next().expect("Event").expect("Parses");
+        assert_eq!(ev.data, "one\ntwo\nthree");
+    }
+
+    #[test]
+    fn trailing_empty_data_line_adds_a_single_newline() {
+        let mut p = Parser::from("data: one\ndata:\n\n");
+        let ev = p.next().expect("Event").expect("Parses");
+        assert_eq!(ev.data, "one\n");
+    }
+
+    #[test]
+    fn retry_directive_is_parsed_as_millis() {
+        let mut p = Parser::from("retry: 2500\n\ndata: hi\n\n");
+        assert_eq!(p.retry(), None);
+
+        p.next();
+        assert_eq!(p.retry(), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn non_numeric_retry_directive_is_ignored() {
+        let mut p = Parser::from("retry: soon\n\n");
+        p.next();
+        assert_eq!(p.retry(), None);
+    }
+
     #[test]
     fn buf_fiddle() {
         let mut buf = BytesMut::from("1234");
@@ -0,0 +1,79 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use tokio::io::AsyncBufRead;
+
+use crate::{parser::Parser, Error, Event};
+
+/// Drives `Parser` off any `AsyncBufRead` source instead of a
+/// `Stream<Item = Result<Bytes, E>>`, so SSE can be read from a file, a
+/// TCP socket, or any other non-HTTP transport. See
+/// [`Parser::from_reader`].
+pub struct FromReader<R> {
+    reader: R,
+    parser: Parser,
+}
+
+impl<R> FromReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            parser: Parser::default(),
+        }
+    }
+}
+
+impl<R> Stream for FromReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    type Item = Result<Event, Error<std::io::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match this.parser.next() {
+                Some(Ok(ev)) => return Poll::Ready(Some(Ok(ev))),
+                Some(Err(err)) => return Poll::Ready(Some(Err(Error::parser(err)))),
+                None => (),
+            }
+
+            match Pin::new(&mut this.reader).poll_fill_buf(ctx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(Error::inner(err)))),
+                Poll::Ready(Ok(buf)) => {
+                    if buf.is_empty() {
+                        return Poll::Ready(None);
+                    }
+
+                    let len = buf.len();
+                    this.parser.put(buf);
+                    Pin::new(&mut this.reader).consume(len);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn reads_events_from_an_async_buf_read() {
+        let cursor = std::io::Cursor::new(&b"data: first\n\ndata: second\n\n"[..]);
+        let mut stream = crate::Parser::from_reader(cursor);
+
+        let ev = stream.next().await.expect("Event").expect("Parses");
+        assert_eq!(ev.data, "first");
+
+        let ev = stream.next().await.expect("Event").expect("Parses");
+        assert_eq!(ev.data, "second");
+
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,207 @@
+use bytes::Buf;
+use futures_core::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{sleep, Sleep};
+
+use crate::{body::Body, Error, Event};
+
+/// Reconnect wait used when the server has not sent a `retry:` directive
+/// yet.
+pub const DEFAULT_RETRY: Duration = Duration::from_secs(3);
+
+enum State<S, Fut> {
+    Connecting(Pin<Box<Fut>>),
+    Connected(Body<S>),
+    Waiting(Pin<Box<Sleep>>),
+}
+
+/// A self-reconnecting SSE client, following the WHATWG `EventSource`
+/// reconnection model.
+///
+/// `EventSource` wraps a `reconnect` closure that produces a fresh byte
+/// stream (e.g. by issuing a new HTTP request) and drives it through a
+/// [`Body`]. Whenever the underlying stream ends or yields an error,
+/// `EventSource` waits for the most recently observed `retry:` interval
+/// (or, before the server sent one, the wait set with `with_retry`,
+/// [`DEFAULT_RETRY`] by default) and calls `reconnect` again, passing the most
+/// recent non-empty `last_event_id` so the closure can set a
+/// `Last-Event-ID` header. Unlike [`Body`], it never terminates on a
+/// recoverable transport error.
+pub struct EventSource<S, B, E, Conn, F, Fut>
+where
+    F: FnMut(Option<&str>) -> Fut,
+    Fut: Future<Output = Result<S, Conn>>,
+{
+    reconnect: F,
+    state: State<S, Fut>,
+    last_event_id: Option<String>,
+    retry: Duration,
+    // `fn() -> _` rather than `(B, E)` directly, so this marker doesn't
+    // drag B/E's Unpin-ness onto EventSource itself.
+    _marker: std::marker::PhantomData<fn() -> (B, E)>,
+}
+
+impl<S, B, E, Conn, F, Fut> EventSource<S, B, E, Conn, F, Fut>
+where
+    F: FnMut(Option<&str>) -> Fut,
+    Fut: Future<Output = Result<S, Conn>>,
+{
+    /// Creates an `EventSource`, immediately invoking `reconnect` to
+    /// establish the first connection.
+    pub fn new(mut reconnect: F) -> Self {
+        let fut = reconnect(None);
+
+        Self {
+            reconnect,
+            state: State::Connecting(Box::pin(fut)),
+            last_event_id: None,
+            retry: DEFAULT_RETRY,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Overrides the wait used before the first reconnect, i.e. until the
+    /// server sends its own `retry:` directive.
+    pub fn with_retry(mut self, retry: Duration) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+impl<S, B, E, Conn, F, Fut> Stream for EventSource<S, B, E, Conn, F, Fut>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: Buf,
+    E: std::error::Error + Unpin,
+    F: FnMut(Option<&str>) -> Fut + Unpin,
+    Fut: Future<Output = Result<S, Conn>>,
+{
+    type Item = Result<Event, Error<E>>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match &mut this.state {
+                State::Connecting(fut) => match fut.as_mut().poll(ctx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(stream)) => this.state = State::Connected(Body::from(stream)),
+                    // The reconnect attempt itself failed (e.g. the HTTP
+                    // request errored before a stream could be produced).
+                    // This is recoverable: wait and try again.
+                    Poll::Ready(Err(_)) => {
+                        this.state = State::Waiting(Box::pin(sleep(this.retry)))
+                    }
+                },
+
+                State::Waiting(delay) => match delay.as_mut().poll(ctx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => {
+                        let fut = (this.reconnect)(this.last_event_id.as_deref());
+                        this.state = State::Connecting(Box::pin(fut));
+                    }
+                },
+
+                State::Connected(body) => match Pin::new(&mut *body).poll_next(ctx) {
+                    Poll::Pending => return Poll::Pending,
+
+                    // Stream ended or errored: both are recoverable here,
+                    // reconnect after the current retry wait. Pick up any
+                    // `retry:` directive the server sent before the
+                    // stream ended, even if it never reached a dispatched
+                    // `Event` (e.g. a `retry:` followed only by comments).
+                    Poll::Ready(None) | Poll::Ready(Some(Err(_))) => {
+                        if let Some(retry) = body.retry() {
+                            this.retry = retry;
+                        }
+
+                        this.state = State::Waiting(Box::pin(sleep(this.retry)))
+                    }
+
+                    Poll::Ready(Some(Ok(ev))) => {
+                        if let Some(retry) = body.retry() {
+                            this.retry = retry;
+                        }
+
+                        if let Some(id) = ev.last_event_id.as_ref().filter(|id| !id.is_empty()) {
+                            this.last_event_id = Some(id.clone());
+                        }
+
+                        return Poll::Ready(Some(Ok(ev)));
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{stream, StreamExt};
+    use std::{
+        fmt,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    #[derive(Debug)]
+    struct BoomError;
+
+    impl fmt::Display for BoomError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "boom")
+        }
+    }
+
+    impl std::error::Error for BoomError {}
+
+    #[tokio::test]
+    async fn reconnects_and_threads_last_event_id() {
+        let attempt = AtomicUsize::new(0);
+
+        let mut source = EventSource::new(move |last_event_id: Option<&str>| {
+            let n = attempt.fetch_add(1, Ordering::SeqCst);
+            let last_event_id = last_event_id.map(String::from);
+
+            async move {
+                match n {
+                    // First connection dispatches a single event, setting
+                    // the last event id to "42".
+                    0 => Ok::<_, BoomError>(stream::iter(vec![Ok(bytes::Bytes::from(
+                        "data: hi\nid: 42\n\n",
+                    ))])),
+
+                    // Second connection sends a `retry:` directive and
+                    // then errors out without ever dispatching an Event.
+                    // The reconnect wait for the *next* attempt must
+                    // still pick up the 1ms retry, and last_event_id must
+                    // still be "42" since no event reset it.
+                    1 => {
+                        assert_eq!(last_event_id.as_deref(), Some("42"));
+                        Ok(stream::iter(vec![
+                            Ok(bytes::Bytes::from("retry: 1\n\n")),
+                            Err(BoomError),
+                        ]))
+                    }
+
+                    _ => {
+                        assert_eq!(last_event_id.as_deref(), Some("42"));
+                        Ok(stream::iter(vec![Ok(bytes::Bytes::from("data: bye\n\n"))]))
+                    }
+                }
+            }
+        })
+        .with_retry(Duration::from_millis(1));
+
+        let ev = source.next().await.expect("Event").expect("Parses");
+        assert_eq!(ev.data, "hi");
+
+        let ev = source.next().await.expect("Event").expect("Parses");
+        assert_eq!(ev.data, "bye");
+    }
+}
@@ -0,0 +1,64 @@
+use bytes::Buf;
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use std::{
+    error::Error as StdError,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{body::Body, Error};
+
+/// Deserializes every event's `data` field as `T`, optionally filtering
+/// by `event` name. See `Body::typed` and `Body::typed_named`.
+pub struct Typed<S, T> {
+    inner: Body<S>,
+    event: Option<String>,
+    // `fn() -> T` rather than `T` directly, so this marker doesn't drag
+    // T's Unpin-ness onto Typed itself.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<S, T> Typed<S, T> {
+    pub(crate) fn new(inner: Body<S>, event: Option<String>) -> Self {
+        Self {
+            inner,
+            event,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, B, E, T> Stream for Typed<S, T>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: Buf,
+    E: StdError + Unpin,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Error<E>>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match Pin::new(&mut this.inner).poll_next(ctx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(Some(Ok(ev))) => {
+                    if let Some(name) = &this.event {
+                        if &ev.event != name {
+                            continue;
+                        }
+                    }
+
+                    return Poll::Ready(Some(
+                        serde_json::from_str(&ev.data).map_err(Error::deserialize),
+                    ));
+                }
+            }
+        }
+    }
+}